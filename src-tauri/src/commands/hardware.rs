@@ -1,30 +1,84 @@
-use crate::{log_debug, log_error, log_internal, log_warn};
-use nvapi;
-use nvapi::UtilizationDomain;
-use std::collections::VecDeque;
+use crate::gpu::{self, GpuBackend};
+use crate::{log_debug, log_internal, log_warn};
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
-use sysinfo::System;
+use sysinfo::{Disks, Networks, System};
 use tauri::command;
 
 pub struct AppState {
   pub system: Arc<Mutex<System>>,
   pub cpu_history: Arc<Mutex<VecDeque<f32>>>,
   pub memory_history: Arc<Mutex<VecDeque<f32>>>,
+  pub gpu_backends: Arc<Mutex<Vec<Box<dyn GpuBackend + Send>>>>,
   pub gpu_history: Arc<Mutex<VecDeque<f32>>>,
-  pub gpu_usage: Arc<Mutex<f32>>,
+  pub gpu_temperature_history: Arc<Mutex<VecDeque<f32>>>,
+  pub gpu_power_history: Arc<Mutex<VecDeque<f32>>>,
+  pub gpu_vram_history: Arc<Mutex<VecDeque<f32>>>,
+  pub gpu_core_clock_history: Arc<Mutex<VecDeque<f32>>>,
+  pub gpu_memory_clock_history: Arc<Mutex<VecDeque<f32>>>,
+  pub last_seen_timestamp: Arc<Mutex<u64>>,
+  pub temperature_history: Arc<Mutex<VecDeque<f32>>>,
+  pub disks: Arc<Mutex<Disks>>,
+  pub networks: Arc<Mutex<Networks>>,
+  pub network_prev_bytes: Arc<Mutex<HashMap<String, (u64, u64)>>>,
+  pub network_rates: Arc<Mutex<HashMap<String, (f32, f32)>>>,
+  pub rx_history: Arc<Mutex<VecDeque<f32>>>,
+  pub tx_history: Arc<Mutex<VecDeque<f32>>>,
+  pub monitor_config: Arc<Mutex<MonitorConfig>>,
 }
 
 ///
-/// システム情報の更新頻度（秒）
+/// ## サンプリング間隔と履歴保持件数の実行時設定
 ///
-const SYSTEM_INFO_INIT_INTERVAL: u64 = 1;
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct MonitorConfig {
+  pub sample_interval_ms: u64,
+  pub history_capacity: usize,
+}
+
+impl Default for MonitorConfig {
+  fn default() -> Self {
+    MonitorConfig {
+      sample_interval_ms: 1000,
+      history_capacity: 60,
+    }
+  }
+}
 
 ///
-/// データを保持する期間（秒）
+/// ## 履歴バッファに値を積み、容量を超えた古い値を切り詰める
+///
+/// `capacity == 0` は「保持しない」ことを意味するため、何も積まずに返す
+/// （`set_monitor_config` が `history_capacity == 0` を拒否するため通常到達しないが、
+/// この関数単体でも無限ループにならないことを保証する）
 ///
-const HISTORY_CAPACITY: usize = 60;
+fn push_bounded(history: &mut VecDeque<f32>, value: f32, capacity: usize) {
+  if capacity == 0 {
+    return;
+  }
+  while history.len() >= capacity {
+    history.pop_front();
+  }
+  history.push_back(value);
+}
+
+///
+/// ## GPU全体（全デバイス集計）のメトリクス
+///
+/// 複数GPUが存在する場合は集計（使用率・クロックは平均、電力・VRAMは合算、温度は最大値）した値
+///
+#[derive(Debug, Clone, Copy, Default)]
+struct GpuMetrics {
+  usage: f32,
+  temperature: f32,
+  power: f32,
+  core_clock: f32,
+  memory_clock: f32,
+  vram_used: f32,
+  vram_total: f32,
+}
 
 ///
 /// ## CPU使用率（%）を取得
@@ -58,15 +112,84 @@ pub fn get_memory_usage(state: tauri::State<'_, AppState>) -> i32 {
 }
 
 ///
-/// ## GPU使用率（%）を取得（Nvidia 限定）
+/// ## デバイスごとのGPU使用率（%）を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `Vec<i32>` 検出されたGPUごとの使用率（%）
+///
+#[command]
+pub fn get_gpu_usage(state: tauri::State<'_, AppState>) -> Vec<i32> {
+  let backends = state.gpu_backends.lock().unwrap();
+  backends
+    .iter()
+    .map(|backend| backend.utilization().unwrap_or(0.0).round() as i32)
+    .collect()
+}
+
+///
+/// ## デバイスごとのGPU温度（℃）を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `Vec<i32>` 検出されたGPUごとの温度（℃）
+///
+#[command]
+pub fn get_gpu_temperature(state: tauri::State<'_, AppState>) -> Vec<i32> {
+  let backends = state.gpu_backends.lock().unwrap();
+  backends
+    .iter()
+    .map(|backend| backend.temperature().unwrap_or(0.0).round() as i32)
+    .collect()
+}
+
+///
+/// ## デバイスごとのGPU消費電力（W）を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `Vec<i32>` 検出されたGPUごとの消費電力（W）
+///
+#[command]
+pub fn get_gpu_power(state: tauri::State<'_, AppState>) -> Vec<i32> {
+  let backends = state.gpu_backends.lock().unwrap();
+  backends
+    .iter()
+    .map(|backend| backend.power().unwrap_or(0.0).round() as i32)
+    .collect()
+}
+
+///
+/// ## デバイスごとのGPUのVRAM使用量を取得
 ///
 /// - param state: `tauri::State<AppState>` アプリケーションの状態
-/// - return: `i32` GPU使用率（%）
+/// - return: `Vec<(i32, i32)>` 検出されたGPUごとの (使用VRAM量（MB）, 総VRAM量（MB）)
 ///
 #[command]
-pub fn get_gpu_usage(state: tauri::State<'_, AppState>) -> i32 {
-  let gpu_usage = state.gpu_usage.lock().unwrap();
-  *gpu_usage as i32
+pub fn get_gpu_vram_usage(state: tauri::State<'_, AppState>) -> Vec<(i32, i32)> {
+  let backends = state.gpu_backends.lock().unwrap();
+  backends
+    .iter()
+    .map(|backend| {
+      let memory = backend.memory().unwrap_or_default();
+      (memory.used_mb.round() as i32, memory.total_mb.round() as i32)
+    })
+    .collect()
+}
+
+///
+/// ## デバイスごとのGPUクロック（MHz）を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `Vec<(i32, i32)>` 検出されたGPUごとの (コアクロック, メモリクロック)
+///
+#[command]
+pub fn get_gpu_clocks(state: tauri::State<'_, AppState>) -> Vec<(i32, i32)> {
+  let backends = state.gpu_backends.lock().unwrap();
+  backends
+    .iter()
+    .map(|backend| {
+      let clocks = backend.clocks().unwrap_or_default();
+      (clocks.core_mhz.round() as i32, clocks.memory_mhz.round() as i32)
+    })
+    .collect()
 }
 
 ///
@@ -100,7 +223,7 @@ pub fn get_memory_usage_history(
 }
 
 ///
-/// ## GPU使用率の履歴を取得
+/// ## GPU使用率（全デバイス平均）の履歴を取得
 ///
 /// - param state: `tauri::State<AppState>` アプリケーションの状態
 /// - param seconds: `usize` 取得する秒数
@@ -114,21 +237,171 @@ pub fn get_gpu_usage_history(
   history.iter().rev().take(seconds).cloned().collect()
 }
 
+///
+/// ## GPU温度（全デバイス最大値）の履歴を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_gpu_temperature_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.gpu_temperature_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
+
+///
+/// ## GPU消費電力（全デバイス合算）の履歴を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_gpu_power_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.gpu_power_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
+
+///
+/// ## GPUのVRAM使用率（全デバイス合算）の履歴を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_gpu_vram_usage_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.gpu_vram_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
+
+///
+/// ## GPUコアクロック（全デバイス平均）の履歴を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_gpu_core_clock_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.gpu_core_clock_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
+
+///
+/// ## GPUメモリクロック（全デバイス平均）の履歴を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_gpu_memory_clock_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.gpu_memory_clock_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
+
+///
+/// ## サンプリング間隔・履歴保持件数の現在値を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `MonitorConfig` 現在の設定値
+///
+#[command]
+pub fn get_monitor_config(state: tauri::State<'_, AppState>) -> MonitorConfig {
+  *state.monitor_config.lock().unwrap()
+}
+
+///
+/// ## サンプリング間隔・履歴保持件数を変更する
+///
+/// 履歴保持件数を縮小した場合は、既存の全履歴バッファを新しい件数に切り詰める。
+/// `history_capacity == 0` では履歴が一切保持されなくなり、`sample_interval_ms == 0` は
+/// サンプラーを busy loop させるため、いずれも不正な値として拒否する
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param config: `MonitorConfig` 新しい設定値
+/// - return: `Result<(), String>` 不正な設定値が指定された場合はエラーメッセージ
+///
+#[command]
+pub fn set_monitor_config(
+  state: tauri::State<'_, AppState>,
+  config: MonitorConfig,
+) -> Result<(), String> {
+  if config.history_capacity == 0 {
+    return Err("history_capacity must be greater than 0".to_string());
+  }
+  if config.sample_interval_ms == 0 {
+    return Err("sample_interval_ms must be greater than 0".to_string());
+  }
+
+  *state.monitor_config.lock().unwrap() = config;
+
+  let histories = [
+    &state.cpu_history,
+    &state.memory_history,
+    &state.gpu_history,
+    &state.gpu_temperature_history,
+    &state.gpu_power_history,
+    &state.gpu_vram_history,
+    &state.gpu_core_clock_history,
+    &state.gpu_memory_clock_history,
+    &state.temperature_history,
+    &state.rx_history,
+    &state.tx_history,
+  ];
+
+  for history in histories {
+    let mut history = history.lock().unwrap();
+    while history.len() > config.history_capacity {
+      history.pop_front();
+    }
+  }
+
+  Ok(())
+}
+
 ///
 /// ## システム情報の初期化
 ///
 /// - param system: `Arc<Mutex<System>>` システム情報
 ///
-/// - `SYSTEM_INFO_INIT_INTERVAL` 秒ごとにCPU使用率とメモリ使用率を更新
+/// - `monitor_config` の `sample_interval_ms` に従い、毎ループ各種メトリクスを更新する
 ///
 pub fn initialize_system(
   system: Arc<Mutex<System>>,
   cpu_history: Arc<Mutex<VecDeque<f32>>>,
   memory_history: Arc<Mutex<VecDeque<f32>>>,
-  gpu_usage: Arc<Mutex<f32>>,
+  gpu_backends: Arc<Mutex<Vec<Box<dyn GpuBackend + Send>>>>,
   gpu_history: Arc<Mutex<VecDeque<f32>>>,
+  gpu_temperature_history: Arc<Mutex<VecDeque<f32>>>,
+  gpu_power_history: Arc<Mutex<VecDeque<f32>>>,
+  gpu_vram_history: Arc<Mutex<VecDeque<f32>>>,
+  gpu_core_clock_history: Arc<Mutex<VecDeque<f32>>>,
+  gpu_memory_clock_history: Arc<Mutex<VecDeque<f32>>>,
+  temperature_history: Arc<Mutex<VecDeque<f32>>>,
+  disks: Arc<Mutex<Disks>>,
+  networks: Arc<Mutex<Networks>>,
+  network_prev_bytes: Arc<Mutex<HashMap<String, (u64, u64)>>>,
+  network_rates: Arc<Mutex<HashMap<String, (f32, f32)>>>,
+  rx_history: Arc<Mutex<VecDeque<f32>>>,
+  tx_history: Arc<Mutex<VecDeque<f32>>>,
+  monitor_config: Arc<Mutex<MonitorConfig>>,
 ) {
   thread::spawn(move || loop {
+    let config = *monitor_config.lock().unwrap();
+
     {
       let mut sys = match system.lock() {
         Ok(s) => s,
@@ -137,6 +410,7 @@ pub fn initialize_system(
 
       sys.refresh_cpu_all();
       sys.refresh_memory();
+      sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
 
       let cpu_usage = {
         let cpus = sys.cpus();
@@ -150,93 +424,224 @@ pub fn initialize_system(
         (used_memory / total_memory * 100.0).round() as f32
       };
 
-      //let gpu_usage_value = match get_gpu_usage() {
-      //  Ok(usage) => usage,
-      //  Err(_) => 0.0, // エラーが発生した場合はデフォルト値として0.0を使用
-      //};
+      let gpu_metrics = {
+        let backends = gpu_backends.lock().unwrap();
+        aggregate_gpu_metrics(&backends)
+      };
 
-      //{
-      //  let mut gpu = gpu_usage.lock().unwrap();
-      //  *gpu = gpu_usage_value;
-      //}
+      let hottest_temperature = {
+        let backends = gpu_backends.lock().unwrap();
+        crate::commands::temperatures::hottest_celsius(&backends)
+      };
 
       {
         let mut cpu_hist = cpu_history.lock().unwrap();
-        if cpu_hist.len() >= HISTORY_CAPACITY {
-          cpu_hist.pop_front();
-        }
-        cpu_hist.push_back(cpu_usage);
+        push_bounded(&mut cpu_hist, cpu_usage, config.history_capacity);
       }
 
       {
         let mut memory_hist = memory_history.lock().unwrap();
-        if memory_hist.len() >= HISTORY_CAPACITY {
-          memory_hist.pop_front();
-        }
-        memory_hist.push_back(memory_usage);
+        push_bounded(&mut memory_hist, memory_usage, config.history_capacity);
       }
 
-      //{
-      //  let mut gpu_hist = gpu_history.lock().unwrap();
-      //  if gpu_hist.len() >= HISTORY_CAPACITY {
-      //    gpu_hist.pop_front();
-      //  }
-      //  gpu_hist.push_back(gpu_usage_value);
-      //}
-    }
+      {
+        let mut gpu_hist = gpu_history.lock().unwrap();
+        push_bounded(&mut gpu_hist, gpu_metrics.usage, config.history_capacity);
+      }
 
-    thread::sleep(Duration::from_secs(SYSTEM_INFO_INIT_INTERVAL));
-  });
+      {
+        let mut temperature_hist = gpu_temperature_history.lock().unwrap();
+        push_bounded(
+          &mut temperature_hist,
+          gpu_metrics.temperature,
+          config.history_capacity,
+        );
+      }
 
-  ///
-  /// TODO GPU使用率を取得する
-  ///
-  #[allow(dead_code)]
-  fn get_gpu_usage() -> Result<f32, nvapi::Status> {
-    log_debug!("start", "get_gpu_usage", None::<&str>);
+      {
+        let mut power_hist = gpu_power_history.lock().unwrap();
+        push_bounded(&mut power_hist, gpu_metrics.power, config.history_capacity);
+      }
 
-    let gpus = nvapi::PhysicalGpu::enumerate()?;
+      {
+        let mut vram_hist = gpu_vram_history.lock().unwrap();
+        let vram_percent = if gpu_metrics.vram_total > 0.0 {
+          (gpu_metrics.vram_used / gpu_metrics.vram_total * 100.0).round()
+        } else {
+          0.0
+        };
+        push_bounded(&mut vram_hist, vram_percent, config.history_capacity);
+      }
 
-    print!("{:?}", gpus);
+      {
+        let mut core_clock_hist = gpu_core_clock_history.lock().unwrap();
+        push_bounded(
+          &mut core_clock_hist,
+          gpu_metrics.core_clock,
+          config.history_capacity,
+        );
+      }
 
-    if gpus.is_empty() {
-      log_warn!("not found", "get_gpu_usage", Some("gpu is not found"));
-      tracing::warn!("gpu is not found");
-      return Err(nvapi::Status::Error); // GPUが見つからない場合はエラーを返す
-    }
+      {
+        let mut memory_clock_hist = gpu_memory_clock_history.lock().unwrap();
+        push_bounded(
+          &mut memory_clock_hist,
+          gpu_metrics.memory_clock,
+          config.history_capacity,
+        );
+      }
+
+      {
+        let mut temperature_hist = temperature_history.lock().unwrap();
+        push_bounded(
+          &mut temperature_hist,
+          hottest_temperature.unwrap_or(0.0),
+          config.history_capacity,
+        );
+      }
 
-    let mut total_usage = 0.0;
-    let mut gpu_count = 0;
+      {
+        let mut disks_list = disks.lock().unwrap();
+        disks_list.refresh(true);
+      }
 
-    for gpu in gpus.iter() {
-      let usage = match gpu.usages() {
-        Ok(usage) => usage,
-        Err(e) => {
-          log_error!("usages_failed", "get_gpu_usage", Some(e.to_string()));
-          return Err(e);
+      {
+        let mut networks_list = networks.lock().unwrap();
+        networks_list.refresh(true);
+
+        let mut prev_bytes = network_prev_bytes.lock().unwrap();
+        let mut rates = network_rates.lock().unwrap();
+        let mut total_rx_rate = 0.0;
+        let mut total_tx_rate = 0.0;
+
+        // インターフェースが消えた（USB NIC抜去、VPNトンネル切断、dockerのveth削除など）場合に
+        // 古いレートが残り続けないよう、今回のtickに存在するものだけで作り直す
+        let mut next_prev_bytes = HashMap::with_capacity(networks_list.len());
+        let mut next_rates = HashMap::with_capacity(networks_list.len());
+
+        for (interface_name, data) in networks_list.iter() {
+          let total_received = data.total_received();
+          let total_transmitted = data.total_transmitted();
+
+          let (prev_received, prev_transmitted) = prev_bytes
+            .get(interface_name)
+            .copied()
+            .unwrap_or((total_received, total_transmitted));
+
+          let interval_secs = config.sample_interval_ms as f32 / 1000.0;
+          let rx_rate = total_received.saturating_sub(prev_received) as f32 / interval_secs;
+          let tx_rate = total_transmitted.saturating_sub(prev_transmitted) as f32 / interval_secs;
+
+          next_prev_bytes.insert(interface_name.clone(), (total_received, total_transmitted));
+          next_rates.insert(interface_name.clone(), (rx_rate, tx_rate));
+
+          total_rx_rate += rx_rate;
+          total_tx_rate += tx_rate;
         }
-      };
 
-      if let Some(gpu_usage) = usage.get(&UtilizationDomain::Graphics) {
-        let usage_f32 = gpu_usage.0 as f32 / 100.0; // Percentage を f32 に変換
-        total_usage += usage_f32;
-        gpu_count += 1;
+        *prev_bytes = next_prev_bytes;
+        *rates = next_rates;
+
+        {
+          let mut rx_hist = rx_history.lock().unwrap();
+          push_bounded(&mut rx_hist, total_rx_rate, config.history_capacity);
+        }
+
+        {
+          let mut tx_hist = tx_history.lock().unwrap();
+          push_bounded(&mut tx_hist, total_tx_rate, config.history_capacity);
+        }
       }
     }
 
-    if gpu_count == 0 {
-      log_warn!(
-        "no_usage",
-        "get_gpu_usage",
-        Some("No GPU usage data collected")
-      );
-      return Err(nvapi::Status::Error); // 使用率が取得できなかった場合のエラーハンドリング
+    thread::sleep(Duration::from_millis(config.sample_interval_ms));
+  });
+}
+
+///
+/// ## 検出済みの全GPUバックエンドからメトリクスを集計する
+///
+/// 使用率・VRAMは合算後に平均・割合へ変換し、電力は合算、温度は最も高い値を採用する。
+/// 1台もGPUが検出できない、またはいずれの読み取りも失敗した場合は0.0で埋める
+///
+fn aggregate_gpu_metrics(backends: &[Box<dyn GpuBackend + Send>]) -> GpuMetrics {
+  log_debug!("start", "aggregate_gpu_metrics", None::<&str>);
+
+  if backends.is_empty() {
+    log_warn!(
+      "not_found",
+      "aggregate_gpu_metrics",
+      Some("gpu is not found")
+    );
+    return GpuMetrics::default();
+  }
+
+  let mut total_usage = 0.0;
+  let mut max_temperature: f32 = 0.0;
+  let mut total_power = 0.0;
+  let mut total_core_clock = 0.0;
+  let mut total_memory_clock = 0.0;
+  let mut total_vram_used = 0.0;
+  let mut total_vram_total = 0.0;
+  let mut usage_count = 0;
+  let mut clock_count = 0;
+
+  for backend in backends {
+    if let Ok(usage) = backend.utilization() {
+      total_usage += usage;
+      usage_count += 1;
+    }
+
+    if let Ok(temperature) = backend.temperature() {
+      max_temperature = max_temperature.max(temperature);
+    }
+
+    if let Ok(power) = backend.power() {
+      total_power += power;
     }
 
-    let average_usage = total_usage / gpu_count as f32;
+    if let Ok(clocks) = backend.clocks() {
+      total_core_clock += clocks.core_mhz;
+      total_memory_clock += clocks.memory_mhz;
+      clock_count += 1;
+    }
 
-    log_debug!("end", "get_gpu_usage", None::<&str>);
+    if let Ok(memory) = backend.memory() {
+      total_vram_used += memory.used_mb;
+      total_vram_total += memory.total_mb;
+    }
+  }
 
-    Ok(average_usage)
+  log_debug!("end", "aggregate_gpu_metrics", None::<&str>);
+
+  GpuMetrics {
+    usage: if usage_count > 0 {
+      total_usage / usage_count as f32
+    } else {
+      0.0
+    },
+    temperature: max_temperature,
+    power: total_power,
+    core_clock: if clock_count > 0 {
+      total_core_clock / clock_count as f32
+    } else {
+      0.0
+    },
+    memory_clock: if clock_count > 0 {
+      total_memory_clock / clock_count as f32
+    } else {
+      0.0
+    },
+    vram_used: total_vram_used,
+    vram_total: total_vram_total,
   }
 }
+
+///
+/// ## 利用可能なGPUバックエンドを検出する
+///
+/// `initialize_system` 呼び出し前のアプリ起動時に一度だけ実行し、`AppState` へ格納する
+///
+pub fn detect_gpu_backends() -> Vec<Box<dyn GpuBackend + Send>> {
+  gpu::detect_backends()
+}
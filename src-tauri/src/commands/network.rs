@@ -0,0 +1,64 @@
+use crate::commands::hardware::AppState;
+use serde::Serialize;
+use tauri::command;
+
+///
+/// ## インターフェースごとのネットワークスループット（バイト/秒）
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkUsage {
+  pub interface_name: String,
+  pub rx_bytes_per_sec: f32,
+  pub tx_bytes_per_sec: f32,
+}
+
+///
+/// ## インターフェースごとの受信・送信スループットを取得
+///
+/// サンプラーが直近2回分の累計バイト数から算出した値を返す
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `Vec<NetworkUsage>` インターフェースごとのスループット
+///
+#[command]
+pub fn get_network_usage(state: tauri::State<'_, AppState>) -> Vec<NetworkUsage> {
+  let rates = state.network_rates.lock().unwrap();
+  rates
+    .iter()
+    .map(|(interface_name, (rx, tx))| NetworkUsage {
+      interface_name: interface_name.clone(),
+      rx_bytes_per_sec: *rx,
+      tx_bytes_per_sec: *tx,
+    })
+    .collect()
+}
+
+///
+/// ## 受信スループット（全インターフェース合算）の履歴を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_network_rx_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.rx_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
+
+///
+/// ## 送信スループット（全インターフェース合算）の履歴を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_network_tx_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.tx_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
@@ -0,0 +1,84 @@
+use crate::commands::hardware::AppState;
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::command;
+
+///
+/// ## プロセスごとのリソース使用状況
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessInfo {
+  pub pid: u32,
+  pub name: String,
+  pub cpu_usage: f32,
+  pub memory_mb: u64,
+  pub gpu_memory_mb: u64,
+  pub gpu_utilization: u32,
+}
+
+///
+/// ## 実行中のプロセス一覧を取得
+///
+/// CPU%・常駐メモリに加え、`GpuBackend::process_stats` に対応したGPU（現状NVIDIAのみ）が
+/// 存在する場合は、プロセスごとのGPUメモリ使用量とSM使用率（`nvidia-smi` の
+/// per-process 表示に相当）を突き合わせて返す
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `Vec<ProcessInfo>` 実行中プロセスのリスト
+///
+#[command]
+pub fn get_processes(state: tauri::State<'_, AppState>) -> Vec<ProcessInfo> {
+  let gpu_stats = collect_gpu_process_stats(&state);
+
+  let system = state.system.lock().unwrap();
+  system
+    .processes()
+    .iter()
+    .map(|(pid, process)| {
+      let pid_u32 = pid.as_u32();
+      let stats = gpu_stats.get(&pid_u32).copied().unwrap_or_default();
+
+      ProcessInfo {
+        pid: pid_u32,
+        name: process.name().to_string_lossy().to_string(),
+        cpu_usage: process.cpu_usage(),
+        memory_mb: process.memory() / 1024 / 1024,
+        gpu_memory_mb: stats.0,
+        gpu_utilization: stats.1,
+      }
+    })
+    .collect()
+}
+
+///
+/// ## 全GPUバックエンドからプロセスごとのGPU使用状況を集計する
+///
+/// 複数GPUにまたがって同一PIDが存在する場合は、GPUメモリは合算、SM使用率は最大値を採用する。
+/// `process_stats` に対応していないバックエンド（AMDなど）は無視する
+///
+fn collect_gpu_process_stats(state: &tauri::State<'_, AppState>) -> HashMap<u32, (u64, u32)> {
+  let backends = state.gpu_backends.lock().unwrap();
+  let mut last_seen_timestamp = state.last_seen_timestamp.lock().unwrap();
+
+  let mut stats: HashMap<u32, (u64, u32)> = HashMap::new();
+  let mut newest_timestamp = *last_seen_timestamp;
+
+  for backend in backends.iter() {
+    let snapshot = match backend.process_stats(*last_seen_timestamp) {
+      Ok(snapshot) => snapshot,
+      Err(_) => continue, // このバックエンドはプロセス単位のGPU統計に非対応
+    };
+
+    for process_stat in snapshot.stats {
+      let entry = stats.entry(process_stat.pid).or_insert((0, 0));
+      entry.0 += process_stat.gpu_memory_mb;
+      entry.1 = entry.1.max(process_stat.sm_util);
+    }
+
+    newest_timestamp = newest_timestamp.max(snapshot.newest_timestamp);
+  }
+
+  *last_seen_timestamp = newest_timestamp;
+
+  stats
+}
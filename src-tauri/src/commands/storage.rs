@@ -0,0 +1,39 @@
+use crate::commands::hardware::AppState;
+use serde::Serialize;
+use tauri::command;
+
+///
+/// ## マウントポイントごとのディスク使用状況
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskInfo {
+  pub mount_point: String,
+  pub file_system: String,
+  pub total_bytes: u64,
+  pub used_bytes: u64,
+}
+
+///
+/// ## マウント済みディスクの一覧を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - return: `Vec<DiskInfo>` マウントポイントごとの使用状況
+///
+#[command]
+pub fn get_disks(state: tauri::State<'_, AppState>) -> Vec<DiskInfo> {
+  let disks = state.disks.lock().unwrap();
+  disks
+    .iter()
+    .map(|disk| {
+      let total_bytes = disk.total_space();
+      let used_bytes = total_bytes.saturating_sub(disk.available_space());
+
+      DiskInfo {
+        mount_point: disk.mount_point().to_string_lossy().to_string(),
+        file_system: disk.file_system().to_string_lossy().to_string(),
+        total_bytes,
+        used_bytes,
+      }
+    })
+    .collect()
+}
@@ -0,0 +1,118 @@
+use crate::commands::hardware::AppState;
+use crate::gpu::GpuBackend;
+use serde::Serialize;
+use sysinfo::Components;
+use tauri::command;
+
+///
+/// ## センサー1つ分の温度情報
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct TemperatureReading {
+  pub label: String,
+  pub celsius: f32,
+  pub fahrenheit: Option<f32>,
+}
+
+///
+/// ## 摂氏を華氏に変換する
+///
+fn to_fahrenheit(celsius: f32) -> f32 {
+  celsius * 9.0 / 5.0 + 32.0
+}
+
+///
+/// ## 利用可能な温度センサーを列挙して取得
+///
+/// `sysinfo` が列挙する全センサー（CPUパッケージ・コアなど）に加えて、検出済みGPUバックエンドの温度も合わせて返す
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param include_fahrenheit: `Option<bool>` `true` の場合、華氏換算値も付与する
+/// - param name_filter: `Option<String>` 指定した場合、ラベルに部分一致するセンサーのみ返す
+/// - return: `Vec<TemperatureReading>` センサーごとの温度
+///
+#[command]
+pub fn get_temperatures(
+  state: tauri::State<'_, AppState>,
+  include_fahrenheit: Option<bool>,
+  name_filter: Option<String>,
+) -> Vec<TemperatureReading> {
+  let include_fahrenheit = include_fahrenheit.unwrap_or(false);
+
+  let mut readings: Vec<(String, f32)> = Components::new_with_refreshed_list()
+    .iter()
+    .filter_map(|component| {
+      component
+        .temperature()
+        .map(|celsius| (component.label().to_string(), celsius))
+    })
+    .map(|(label, celsius)| (label, celsius))
+    .collect();
+
+  {
+    let backends = state.gpu_backends.lock().unwrap();
+    for (index, backend) in backends.iter().enumerate() {
+      if let Ok(celsius) = backend.temperature() {
+        readings.push((format!("GPU #{} ({})", index, backend.name()), celsius));
+      }
+    }
+  }
+
+  readings
+    .into_iter()
+    .filter(|(label, _)| {
+      name_filter
+        .as_ref()
+        .map(|filter| label.to_lowercase().contains(&filter.to_lowercase()))
+        .unwrap_or(true)
+    })
+    .map(|(label, celsius)| TemperatureReading {
+      label,
+      celsius,
+      fahrenheit: include_fahrenheit.then(|| to_fahrenheit(celsius)),
+    })
+    .collect()
+}
+
+///
+/// ## 温度履歴（最も高いセンサー値）を取得
+///
+/// - param state: `tauri::State<AppState>` アプリケーションの状態
+/// - param seconds: `usize` 取得する秒数
+///
+#[command]
+pub fn get_temperature_history(
+  state: tauri::State<'_, AppState>,
+  seconds: usize,
+) -> Vec<f32> {
+  let history = state.temperature_history.lock().unwrap();
+  history.iter().rev().take(seconds).cloned().collect()
+}
+
+///
+/// ## `sysinfo` が列挙する全センサー（CPUパッケージ／コアなど）とGPUセンサーの中から最も高い温度（℃）を求める
+///
+/// 1件もセンサーが取得できない場合は `None` を返す
+///
+pub fn hottest_celsius(backends: &[Box<dyn GpuBackend + Send>]) -> Option<f32> {
+  let cpu_hottest = Components::new_with_refreshed_list()
+    .iter()
+    .filter_map(|component| component.temperature())
+    .fold(None, |max: Option<f32>, celsius| {
+      Some(max.map_or(celsius, |m| m.max(celsius)))
+    });
+
+  let gpu_hottest = backends
+    .iter()
+    .filter_map(|backend| backend.temperature().ok())
+    .fold(None, |max: Option<f32>, celsius| {
+      Some(max.map_or(celsius, |m| m.max(celsius)))
+    });
+
+  match (cpu_hottest, gpu_hottest) {
+    (Some(cpu), Some(gpu)) => Some(cpu.max(gpu)),
+    (Some(cpu), None) => Some(cpu),
+    (None, Some(gpu)) => Some(gpu),
+    (None, None) => None,
+  }
+}
@@ -0,0 +1,123 @@
+use super::{GpuBackend, GpuClocks, GpuError, GpuMemory, GpuResult};
+#[cfg(target_os = "linux")]
+use std::sync::{Arc, Mutex};
+
+///
+/// ## AMD GPU向けの `GpuBackend` 実装
+///
+/// Linux上のROCm SMI（`rocm_smi`）を介して物理GPU1枚分のメトリクスを取得する。
+/// ROCm SMIの初期化（ドライバへの接続）は `enumerate` 時に1度だけ行い、
+/// 同一プロセス内の全AMDデバイスでハンドルを共有する
+///
+pub struct AmdBackend {
+  #[cfg(target_os = "linux")]
+  rsmi: Arc<Mutex<rocm_smi::RocmSmi>>,
+  device_index: u32,
+}
+
+impl AmdBackend {
+  ///
+  /// ## システムに搭載されたAMD GPUを列挙する
+  ///
+  /// Linux以外では空のリストを返す。LinuxでROCm SMIの初期化に失敗した場合（ドライバ未導入など）は
+  /// `GpuError::Amd` を返す
+  ///
+  #[cfg(target_os = "linux")]
+  pub fn enumerate() -> GpuResult<Vec<AmdBackend>> {
+    let rsmi = rocm_smi::RocmSmi::init().map_err(|e| GpuError::Amd(e.to_string()))?;
+    let device_count = rsmi.device_count().map_err(|e| GpuError::Amd(e.to_string()))?;
+    let rsmi = Arc::new(Mutex::new(rsmi));
+
+    Ok(
+      (0..device_count)
+        .map(|device_index| AmdBackend {
+          rsmi: Arc::clone(&rsmi),
+          device_index,
+        })
+        .collect(),
+    )
+  }
+
+  #[cfg(not(target_os = "linux"))]
+  pub fn enumerate() -> GpuResult<Vec<AmdBackend>> {
+    Ok(Vec::new())
+  }
+
+  #[cfg(target_os = "linux")]
+  fn with_rsmi<T>(&self, f: impl FnOnce(&rocm_smi::RocmSmi, u32) -> rocm_smi::RocmSmiResult<T>) -> GpuResult<T> {
+    let rsmi = self.rsmi.lock().unwrap();
+    f(&rsmi, self.device_index).map_err(|e| GpuError::Amd(e.to_string()))
+  }
+}
+
+#[cfg(target_os = "linux")]
+impl GpuBackend for AmdBackend {
+  fn name(&self) -> String {
+    self
+      .with_rsmi(|rsmi, index| rsmi.get_device_identifiers(index).map(|id| id.name))
+      .unwrap_or_else(|_| format!("AMD GPU #{}", self.device_index))
+  }
+
+  fn utilization(&self) -> GpuResult<f32> {
+    self.with_rsmi(|rsmi, index| rsmi.get_device_utilization_percent(index))
+      .map(|percent| percent as f32)
+  }
+
+  fn temperature(&self) -> GpuResult<f32> {
+    self
+      .with_rsmi(|rsmi, index| rsmi.get_device_temperature(index, rocm_smi::TemperatureSensor::Edge))
+      .map(|millidegrees| millidegrees as f32 / 1000.0)
+  }
+
+  fn power(&self) -> GpuResult<f32> {
+    self
+      .with_rsmi(|rsmi, index| rsmi.get_device_average_power(index))
+      .map(|microwatts| microwatts as f32 / 1_000_000.0)
+  }
+
+  fn clocks(&self) -> GpuResult<GpuClocks> {
+    let core_hz = self.with_rsmi(|rsmi, index| rsmi.get_device_clock(index, rocm_smi::ClockType::System))?;
+    let memory_hz = self.with_rsmi(|rsmi, index| rsmi.get_device_clock(index, rocm_smi::ClockType::Memory))?;
+
+    Ok(GpuClocks {
+      core_mhz: core_hz as f32 / 1_000_000.0,
+      memory_mhz: memory_hz as f32 / 1_000_000.0,
+    })
+  }
+
+  fn memory(&self) -> GpuResult<GpuMemory> {
+    let usage = self.with_rsmi(|rsmi, index| rsmi.get_device_memory_usage(index, rocm_smi::MemoryType::Vram))?;
+
+    Ok(GpuMemory {
+      used_mb: usage.used as f32 / 1024.0 / 1024.0,
+      total_mb: usage.total as f32 / 1024.0 / 1024.0,
+    })
+  }
+}
+
+#[cfg(not(target_os = "linux"))]
+impl GpuBackend for AmdBackend {
+  fn name(&self) -> String {
+    format!("AMD GPU #{}", self.device_index)
+  }
+
+  fn utilization(&self) -> GpuResult<f32> {
+    Err(GpuError::Unavailable)
+  }
+
+  fn temperature(&self) -> GpuResult<f32> {
+    Err(GpuError::Unavailable)
+  }
+
+  fn power(&self) -> GpuResult<f32> {
+    Err(GpuError::Unavailable)
+  }
+
+  fn clocks(&self) -> GpuResult<GpuClocks> {
+    Err(GpuError::Unavailable)
+  }
+
+  fn memory(&self) -> GpuResult<GpuMemory> {
+    Err(GpuError::Unavailable)
+  }
+}
@@ -0,0 +1,148 @@
+mod amd;
+mod nvidia;
+
+pub use amd::AmdBackend;
+pub use nvidia::NvidiaBackend;
+
+use crate::{log_debug, log_warn};
+
+///
+/// ## GPUバックエンド共通のエラー型
+///
+#[derive(Debug)]
+pub enum GpuError {
+  Nvidia(nvapi::Status),
+  Nvml(String),
+  Amd(String),
+  Unavailable,
+}
+
+impl std::fmt::Display for GpuError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      GpuError::Nvidia(status) => write!(f, "nvidia backend error: {status:?}"),
+      GpuError::Nvml(message) => write!(f, "nvml error: {message}"),
+      GpuError::Amd(message) => write!(f, "amd backend error: {message}"),
+      GpuError::Unavailable => write!(f, "gpu backend unavailable"),
+    }
+  }
+}
+
+impl std::error::Error for GpuError {}
+
+impl From<nvapi::Status> for GpuError {
+  fn from(status: nvapi::Status) -> Self {
+    GpuError::Nvidia(status)
+  }
+}
+
+pub type GpuResult<T> = Result<T, GpuError>;
+
+///
+/// ## クロック周波数（MHz）
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GpuClocks {
+  pub core_mhz: f32,
+  pub memory_mhz: f32,
+}
+
+///
+/// ## VRAM使用量（MB）
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GpuMemory {
+  pub used_mb: f32,
+  pub total_mb: f32,
+}
+
+///
+/// ## プロセス単位のGPU使用状況
+///
+#[derive(Debug, Clone, Copy)]
+pub struct GpuProcessStat {
+  pub pid: u32,
+  pub gpu_memory_mb: u64,
+  pub sm_util: u32,
+}
+
+///
+/// ## `process_stats` の呼び出し結果
+///
+/// `newest_timestamp` は次回呼び出しの `since_timestamp` にそのまま使う
+/// （新しいサンプルが無かった場合は呼び出し時の `since_timestamp` をそのまま返す）
+///
+#[derive(Debug, Clone, Default)]
+pub struct GpuProcessSnapshot {
+  pub stats: Vec<GpuProcessStat>,
+  pub newest_timestamp: u64,
+}
+
+///
+/// ## ベンダー非依存のGPUバックエンド
+///
+/// NVIDIA・AMDなど、物理GPU単位で実装を差し替えられるようにするためのトレイト
+///
+pub trait GpuBackend: Send {
+  /// GPUの表示名
+  fn name(&self) -> String;
+  /// GPU使用率（%）
+  fn utilization(&self) -> GpuResult<f32>;
+  /// GPU温度（℃）
+  fn temperature(&self) -> GpuResult<f32>;
+  /// GPU消費電力（W）
+  fn power(&self) -> GpuResult<f32>;
+  /// コア・メモリクロック（MHz）
+  fn clocks(&self) -> GpuResult<GpuClocks>;
+  /// VRAM使用量（MB）
+  fn memory(&self) -> GpuResult<GpuMemory>;
+
+  ///
+  /// プロセスごとのGPUメモリ使用量・SM使用率を取得する
+  ///
+  /// `since_timestamp`（マイクロ秒epoch）以降に記録されたサンプルのみを対象とする。
+  /// 対応していないバックエンドではデフォルトで `GpuError::Unavailable` を返す
+  ///
+  fn process_stats(&self, since_timestamp: u64) -> GpuResult<GpuProcessSnapshot> {
+    let _ = since_timestamp;
+    Err(GpuError::Unavailable)
+  }
+}
+
+///
+/// ## 利用可能なGPUバックエンドを検出する
+///
+/// NVIDIA（nvapi/NVML）とAMD（ROCm SMI、Linuxのみ）をそれぞれ検出し、
+/// 見つかった物理GPUごとに1つの `GpuBackend` としてまとめて返す
+///
+pub fn detect_backends() -> Vec<Box<dyn GpuBackend + Send>> {
+  log_debug!("start", "detect_backends", None::<&str>);
+
+  let mut backends: Vec<Box<dyn GpuBackend + Send>> = Vec::new();
+
+  match NvidiaBackend::enumerate() {
+    Ok(nvidia_backends) => {
+      for backend in nvidia_backends {
+        backends.push(Box::new(backend));
+      }
+    }
+    Err(e) => {
+      log_warn!("nvidia_unavailable", "detect_backends", Some(e.to_string()));
+    }
+  }
+
+  match AmdBackend::enumerate() {
+    Ok(amd_backends) => {
+      for backend in amd_backends {
+        backends.push(Box::new(backend));
+      }
+    }
+    Err(e) => {
+      log_warn!("amd_unavailable", "detect_backends", Some(e.to_string()));
+    }
+  }
+
+  log_debug!("end", "detect_backends", None::<&str>);
+
+  backends
+}
@@ -0,0 +1,152 @@
+use super::{GpuBackend, GpuClocks, GpuError, GpuMemory, GpuProcessSnapshot, GpuProcessStat, GpuResult};
+use nvapi::{ClockDomain, ClockFrequencyType, PhysicalGpu, UtilizationDomain};
+use nvml_wrapper::enums::device::UsedGpuMemory;
+use nvml_wrapper::Nvml;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+///
+/// ## NVIDIA GPU向けの `GpuBackend` 実装
+///
+/// 使用率・温度・電力・クロック・VRAMはnvapi経由、プロセスごとのGPU利用状況は
+/// `nvml_wrapper` 経由で取得する。`Nvml::init()` はドライバへの接続を伴うため
+/// `enumerate` 時に1度だけ行い、同一プロセス内の全NVIDIAデバイスでハンドルを共有する。
+/// nvapiとNVMLのデバイス列挙順は同一であることを前提に、インデックスで対応付ける
+///
+pub struct NvidiaBackend {
+  gpu: PhysicalGpu,
+  nvml: Option<Arc<Nvml>>,
+  device_index: u32,
+}
+
+impl NvidiaBackend {
+  ///
+  /// ## システムに搭載されたNVIDIA GPUを列挙する
+  ///
+  /// NVMLの初期化に失敗した場合でも、nvapiベースのメトリクス（使用率・温度・電力・
+  /// クロック・VRAM）は取得できるように、`process_stats` のみ非対応として続行する
+  ///
+  pub fn enumerate() -> GpuResult<Vec<NvidiaBackend>> {
+    let gpus = PhysicalGpu::enumerate()?;
+    let nvml = Nvml::init().ok().map(Arc::new);
+
+    Ok(
+      gpus
+        .into_iter()
+        .enumerate()
+        .map(|(index, gpu)| NvidiaBackend {
+          gpu,
+          nvml: nvml.clone(),
+          device_index: index as u32,
+        })
+        .collect(),
+    )
+  }
+}
+
+impl GpuBackend for NvidiaBackend {
+  fn name(&self) -> String {
+    self
+      .gpu
+      .full_name()
+      .unwrap_or_else(|_| "NVIDIA GPU".to_string())
+  }
+
+  fn utilization(&self) -> GpuResult<f32> {
+    let usage = self.gpu.usages().map_err(GpuError::from)?;
+    let graphics = usage
+      .get(&UtilizationDomain::Graphics)
+      .ok_or(GpuError::Unavailable)?;
+    Ok(graphics.0 as f32 / 100.0)
+  }
+
+  fn temperature(&self) -> GpuResult<f32> {
+    let thermal = self.gpu.thermal_settings(None).map_err(GpuError::from)?;
+    thermal
+      .iter()
+      .map(|sensor| sensor.current_temp.0 as f32)
+      .fold(None, |max, temp| Some(max.map_or(temp, |m: f32| m.max(temp))))
+      .ok_or(GpuError::Unavailable)
+  }
+
+  fn power(&self) -> GpuResult<f32> {
+    let power = self.gpu.power_usage().map_err(GpuError::from)?;
+    Ok(power.0 as f32 / 1000.0) // mW を W に変換
+  }
+
+  fn clocks(&self) -> GpuResult<GpuClocks> {
+    let clocks = self
+      .gpu
+      .clock_frequencies(ClockFrequencyType::Current)
+      .map_err(GpuError::from)?;
+
+    Ok(GpuClocks {
+      core_mhz: clocks
+        .get(&ClockDomain::Graphics)
+        .map(|c| c.0 as f32 / 1000.0)
+        .unwrap_or(0.0),
+      memory_mhz: clocks
+        .get(&ClockDomain::Memory)
+        .map(|c| c.0 as f32 / 1000.0)
+        .unwrap_or(0.0),
+    })
+  }
+
+  fn memory(&self) -> GpuResult<GpuMemory> {
+    let memory_info = self.gpu.memory_info().map_err(GpuError::from)?;
+    let total_bytes = memory_info.dedicated.0 as f32;
+    let available_bytes = memory_info.dedicated_available.0 as f32;
+
+    Ok(GpuMemory {
+      used_mb: (total_bytes - available_bytes) / 1024.0 / 1024.0,
+      total_mb: total_bytes / 1024.0 / 1024.0,
+    })
+  }
+
+  fn process_stats(&self, since_timestamp: u64) -> GpuResult<GpuProcessSnapshot> {
+    let nvml = self.nvml.as_ref().ok_or(GpuError::Unavailable)?;
+    let device = nvml
+      .device_by_index(self.device_index)
+      .map_err(|e| GpuError::Nvml(e.to_string()))?;
+
+    let mut stats: HashMap<u32, GpuProcessStat> = HashMap::new();
+    let mut newest_timestamp = since_timestamp;
+
+    // アイドル状態でサンプルが1件も無いのは正常系であり、エラーとしては扱わない
+    let utilization_samples = device
+      .process_utilization_stats(since_timestamp)
+      .unwrap_or_default();
+
+    for sample in utilization_samples {
+      let entry = stats.entry(sample.pid).or_insert(GpuProcessStat {
+        pid: sample.pid,
+        gpu_memory_mb: 0,
+        sm_util: 0,
+      });
+      entry.sm_util = entry.sm_util.max(sample.sm_util);
+      newest_timestamp = newest_timestamp.max(sample.timestamp);
+    }
+
+    let graphics_processes = device.running_graphics_processes().unwrap_or_default();
+    let compute_processes = device.running_compute_processes().unwrap_or_default();
+
+    for process in graphics_processes.into_iter().chain(compute_processes) {
+      let used_memory_mb = match process.used_gpu_memory {
+        UsedGpuMemory::Used(bytes) => bytes / 1024 / 1024,
+        UsedGpuMemory::Unavailable => 0,
+      };
+
+      let entry = stats.entry(process.pid).or_insert(GpuProcessStat {
+        pid: process.pid,
+        gpu_memory_mb: 0,
+        sm_util: 0,
+      });
+      entry.gpu_memory_mb += used_memory_mb;
+    }
+
+    Ok(GpuProcessSnapshot {
+      stats: stats.into_values().collect(),
+      newest_timestamp,
+    })
+  }
+}